@@ -8,16 +8,18 @@ use std::{
 use obs_sys::{
     obs_data_array_count, obs_data_array_item, obs_data_array_release, obs_data_array_t,
     obs_data_clear, obs_data_create, obs_data_create_from_json, obs_data_create_from_json_file,
-    obs_data_create_from_json_file_safe, obs_data_erase, obs_data_get_json, obs_data_item_byname,
-    obs_data_item_get_array, obs_data_item_get_bool, obs_data_item_get_double,
-    obs_data_item_get_int, obs_data_item_get_obj, obs_data_item_get_string, obs_data_item_gettype,
+    obs_data_create_from_json_file_safe, obs_data_erase, obs_data_first, obs_data_get_json,
+    obs_data_item_byname, obs_data_item_get_array, obs_data_item_get_bool,
+    obs_data_item_get_double, obs_data_item_get_int, obs_data_item_get_name,
+    obs_data_item_get_obj, obs_data_item_get_string, obs_data_item_gettype, obs_data_item_next,
     obs_data_item_numtype, obs_data_item_release, obs_data_item_t, obs_data_number_type,
     obs_data_number_type_OBS_DATA_NUM_DOUBLE, obs_data_number_type_OBS_DATA_NUM_INT,
-    obs_data_release, obs_data_set_default_bool, obs_data_set_default_double,
-    obs_data_set_default_int, obs_data_set_default_obj, obs_data_set_default_string, obs_data_t,
-    obs_data_type, obs_data_type_OBS_DATA_ARRAY, obs_data_type_OBS_DATA_BOOLEAN,
-    obs_data_type_OBS_DATA_NUMBER, obs_data_type_OBS_DATA_OBJECT, obs_data_type_OBS_DATA_STRING,
-    size_t,
+    obs_data_release, obs_data_set_array, obs_data_set_bool, obs_data_set_default_bool,
+    obs_data_set_default_double, obs_data_set_default_int, obs_data_set_default_obj,
+    obs_data_set_default_string, obs_data_set_double, obs_data_set_int, obs_data_set_obj,
+    obs_data_set_string, obs_data_t, obs_data_type, obs_data_type_OBS_DATA_ARRAY,
+    obs_data_type_OBS_DATA_BOOLEAN, obs_data_type_OBS_DATA_NUMBER, obs_data_type_OBS_DATA_OBJECT,
+    obs_data_type_OBS_DATA_STRING, size_t,
 };
 
 use crate::{
@@ -187,6 +189,70 @@ impl FromDataItem for DataArray<'_> {
     }
 }
 
+pub trait IntoDataItem: Sized {
+    /// # Safety
+    ///
+    /// Pointer must be valid.
+    unsafe fn set_unchecked(obj: *mut obs_data_t, name: ObsString, val: Self);
+}
+
+impl IntoDataItem for Cow<'_, str> {
+    unsafe fn set_unchecked(obj: *mut obs_data_t, name: ObsString, val: Self) {
+        let s = CString::new(val.as_ref()).unwrap();
+        obs_data_set_string(obj, name.as_ptr(), s.as_ptr());
+    }
+}
+
+impl IntoDataItem for ObsString {
+    unsafe fn set_unchecked(obj: *mut obs_data_t, name: ObsString, val: Self) {
+        obs_data_set_string(obj, name.as_ptr(), val.as_ptr());
+    }
+}
+
+macro_rules! impl_set_int {
+    ($($t:ty)*) => {
+        $(
+            impl IntoDataItem for $t {
+                unsafe fn set_unchecked(obj: *mut obs_data_t, name: ObsString, val: Self) {
+                    obs_data_set_int(obj, name.as_ptr(), val as i64)
+                }
+            }
+        )*
+    };
+}
+
+impl_set_int!(i64 u64 i32 u32 i16 u16 i8 u8 isize usize);
+
+impl IntoDataItem for f64 {
+    unsafe fn set_unchecked(obj: *mut obs_data_t, name: ObsString, val: Self) {
+        obs_data_set_double(obj, name.as_ptr(), val)
+    }
+}
+
+impl IntoDataItem for f32 {
+    unsafe fn set_unchecked(obj: *mut obs_data_t, name: ObsString, val: Self) {
+        obs_data_set_double(obj, name.as_ptr(), val as f64)
+    }
+}
+
+impl IntoDataItem for bool {
+    unsafe fn set_unchecked(obj: *mut obs_data_t, name: ObsString, val: Self) {
+        obs_data_set_bool(obj, name.as_ptr(), val)
+    }
+}
+
+impl IntoDataItem for DataObj<'_> {
+    unsafe fn set_unchecked(obj: *mut obs_data_t, name: ObsString, val: Self) {
+        obs_data_set_obj(obj, name.as_ptr(), val.as_ptr_mut())
+    }
+}
+
+impl IntoDataItem for DataArray<'_> {
+    unsafe fn set_unchecked(obj: *mut obs_data_t, name: ObsString, val: Self) {
+        obs_data_set_array(obj, name.as_ptr(), val.as_ptr_mut())
+    }
+}
+
 /// A smart pointer to `obs_data_t`
 pub struct DataObj<'parent> {
     raw: *mut obs_data_t,
@@ -287,6 +353,18 @@ impl DataObj<'_> {
         unsafe { T::set_default_unchecked(self.as_ptr_mut(), name.into(), value.into()) }
     }
 
+    /// Sets a live value for the key, overwriting any existing value.
+    pub fn set<T: IntoDataItem>(&mut self, name: impl Into<ObsString>, value: T) {
+        unsafe { T::set_unchecked(self.as_ptr_mut(), name.into(), value) }
+    }
+
+    /// Sets a live [`DataArray`] value for the key. `set` also accepts a
+    /// [`DataArray`] directly, this is a convenience wrapper for callers that
+    /// don't want to spell out the type parameter.
+    pub fn set_array(&mut self, name: impl Into<ObsString>, value: DataArray) {
+        self.set(name, value)
+    }
+
     /// Creates a JSON representation of this object.
     pub fn get_json(&self) -> Option<String> {
         unsafe {
@@ -308,6 +386,97 @@ impl DataObj<'_> {
             obs_data_erase(self.raw, name.as_ptr());
         }
     }
+
+    /// Iterates over this object's items, without needing to know their
+    /// names ahead of time.
+    pub fn iter(&self) -> DataItems<'_> {
+        // Unlike `get`'s byname lookup, the reference `obs_data_first` hands
+        // back is not released immediately: `obs_data_item_next` consumes it
+        // itself as it advances, so the iterator holds onto it until then.
+        DataItems {
+            item: unsafe { obs_data_first(self.raw) },
+            _parent: PhantomData,
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a DataObj<'_> {
+    type Item = DataItem<'a>;
+    type IntoIter = DataItems<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// A borrowed item yielded by [`DataObj::iter`]. Stays valid for as long as
+/// the [`DataObj`] it came from does, since it just points into the data
+/// already owned by that object.
+pub struct DataItem<'a> {
+    ptr: *mut obs_data_item_t,
+    _parent: PhantomData<&'a DataObj<'a>>,
+}
+
+impl DataItem<'_> {
+    /// The item's key.
+    pub fn name(&self) -> Cow<str> {
+        unsafe { CStr::from_ptr(obs_data_item_get_name(self.ptr)).to_string_lossy() }
+    }
+
+    /// The item's type.
+    pub fn typ(&self) -> DataType {
+        unsafe { DataType::from_item(self.ptr) }
+    }
+
+    /// Fetches the item's value, if it is of type `T`.
+    pub fn get<T: FromDataItem>(&self) -> Option<T> {
+        if self.typ() == T::typ() {
+            unsafe { T::from_item_unchecked(self.ptr) }
+        } else {
+            None
+        }
+    }
+}
+
+/// An iterator over a [`DataObj`]'s items. See [`DataObj::iter`].
+///
+/// `item` holds the one reference `obs_data_first`/`obs_data_item_next`
+/// hands back for the current traversal position. Each step yields that
+/// pointer directly as a [`DataItem`] and then hands it to
+/// `obs_data_item_next`, which releases it as it advances `item` to the
+/// next one. If iteration stops before running out of items, `Drop`
+/// releases whatever reference is still held.
+pub struct DataItems<'a> {
+    item: *mut obs_data_item_t,
+    _parent: PhantomData<&'a DataObj<'a>>,
+}
+
+impl<'a> Iterator for DataItems<'a> {
+    type Item = DataItem<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.item.is_null() {
+            return None;
+        }
+        let current = self.item;
+        unsafe {
+            obs_data_item_next(&mut self.item);
+        }
+        Some(DataItem {
+            ptr: current,
+            _parent: PhantomData,
+        })
+    }
+}
+
+impl Drop for DataItems<'_> {
+    fn drop(&mut self) {
+        if !self.item.is_null() {
+            unsafe {
+                obs_data_item_release(&mut self.item);
+            }
+        }
+    }
 }
 
 pub struct DataArray<'parent> {
@@ -345,4 +514,1207 @@ impl DataArray<'_> {
         let ptr = unsafe { obs_data_array_item(self.raw, index as size_t) };
         unsafe { DataObj::from_raw_unchecked(ptr) }
     }
+
+    /// Iterates over this array's items in order.
+    pub fn iter(&self) -> DataArrayItems<'_> {
+        DataArrayItems {
+            array: self,
+            index: 0,
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a DataArray<'_> {
+    type Item = DataObj<'a>;
+    type IntoIter = DataArrayItems<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over a [`DataArray`]'s items. See [`DataArray::iter`].
+pub struct DataArrayItems<'a> {
+    array: &'a DataArray<'a>,
+    index: usize,
+}
+
+impl<'a> Iterator for DataArrayItems<'a> {
+    type Item = DataObj<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.array.get(self.index)?;
+        self.index += 1;
+        Some(item)
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde_support {
+    //! Bridges [`DataObj`] to the `serde` data model, so callers can convert
+    //! to/from their own `#[derive(Serialize, Deserialize)]` types directly,
+    //! without round-tripping through `get_json`/`from_json` strings.
+
+    use std::ffi::CStr;
+    use std::fmt;
+
+    use obs_sys::{
+        obs_data_array_create, obs_data_array_push_back, obs_data_array_t, obs_data_item_byname,
+        obs_data_item_get_array, obs_data_item_get_bool, obs_data_item_get_double,
+        obs_data_item_get_int, obs_data_item_get_obj, obs_data_item_get_string,
+        obs_data_item_release, obs_data_item_t, obs_data_set_array, obs_data_set_bool,
+        obs_data_set_double, obs_data_set_int, obs_data_set_obj, obs_data_set_string, obs_data_t,
+    };
+    use serde::{
+        de::{self, DeserializeOwned, IntoDeserializer},
+        ser::{self, SerializeMap, SerializeSeq, SerializeStruct, SerializeTuple},
+        Serialize,
+    };
+
+    use super::{DataArray, DataObj, DataType};
+    use crate::wrapper::PtrWrapper;
+
+    /// A borrowed data item, used only to drive deserialization of a single
+    /// field's value (see [`StructMapAccess`]).
+    struct DataItem {
+        ptr: *mut obs_data_item_t,
+    }
+
+    impl DataItem {
+        fn typ(&self) -> DataType {
+            unsafe { DataType::from_item(self.ptr) }
+        }
+
+        fn get_string(&self) -> Option<String> {
+            unsafe {
+                let ptr = obs_data_item_get_string(self.ptr);
+                if ptr.is_null() {
+                    return None;
+                }
+                Some(CStr::from_ptr(ptr).to_string_lossy().into_owned())
+            }
+        }
+
+        fn get_int(&self) -> i64 {
+            unsafe { obs_data_item_get_int(self.ptr) }
+        }
+
+        fn get_double(&self) -> f64 {
+            unsafe { obs_data_item_get_double(self.ptr) }
+        }
+
+        fn get_bool(&self) -> bool {
+            unsafe { obs_data_item_get_bool(self.ptr) }
+        }
+
+        fn get_obj(&self) -> Option<DataObj<'static>> {
+            unsafe { DataObj::from_raw_unchecked(obs_data_item_get_obj(self.ptr)) }
+        }
+
+        fn get_array(&self) -> Option<DataArray<'static>> {
+            unsafe { DataArray::from_raw_unchecked(obs_data_item_get_array(self.ptr)) }
+        }
+    }
+
+    /// Looks up `name` on `obj`, mirroring the lookup/release dance in
+    /// [`DataObj::get`].
+    fn item_by_name(obj: &DataObj, name: &str) -> Option<DataItem> {
+        let name = crate::string::ObsString::from(name);
+        let mut ptr = unsafe { obs_data_item_byname(obj.as_ptr() as *mut _, name.as_ptr()) };
+        if ptr.is_null() {
+            return None;
+        }
+        unsafe { obs_data_item_release(&mut ptr) };
+        assert!(!ptr.is_null()); // We should not be the last holder
+        Some(DataItem { ptr })
+    }
+
+    /// Error produced while converting between a Rust value and a [`DataObj`].
+    #[derive(Debug)]
+    pub enum Error {
+        Message(String),
+        MissingField(&'static str),
+        TypeMismatch {
+            expected: DataType,
+            found: Option<DataType>,
+        },
+    }
+
+    impl fmt::Display for Error {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                Error::Message(msg) => f.write_str(msg),
+                Error::MissingField(name) => write!(f, "missing field `{name}`"),
+                Error::TypeMismatch { expected, found } => {
+                    write!(f, "expected a value of type {expected:?}, found {found:?}")
+                }
+            }
+        }
+    }
+
+    impl std::error::Error for Error {}
+
+    impl ser::Error for Error {
+        fn custom<T: fmt::Display>(msg: T) -> Self {
+            Error::Message(msg.to_string())
+        }
+    }
+
+    impl de::Error for Error {
+        fn custom<T: fmt::Display>(msg: T) -> Self {
+            Error::Message(msg.to_string())
+        }
+    }
+
+    impl DataObj<'_> {
+        /// Builds a [`DataObj`] from any `T: Serialize`, writing its fields
+        /// with `obs_data_set_*` instead of allocating an intermediate JSON
+        /// string.
+        ///
+        /// `T` must serialize as a struct or map, since a [`DataObj`] is
+        /// always a key/value container.
+        pub fn from_serialize<T: Serialize>(value: &T) -> Result<Self, Error> {
+            let obj = DataObj::new();
+            value.serialize(TopSerializer {
+                obj: unsafe { obj.as_ptr_mut() },
+            })?;
+            Ok(obj)
+        }
+
+        /// Reconstructs a `T: DeserializeOwned` by walking this object's
+        /// items, using the same [`DataType`] discrimination as
+        /// [`DataObj::get`].
+        pub fn deserialize<T: DeserializeOwned>(&self) -> Result<T, Error> {
+            T::deserialize(DataObjDeserializer { obj: self })
+        }
+    }
+
+    /// Common field-writing logic shared by struct and map serialization,
+    /// regardless of whether the object being filled is the top-level
+    /// [`DataObj`], a nested field, or an array element.
+    enum BuilderObj {
+        /// Fields are written directly into an object owned by the caller.
+        Borrowed(*mut obs_data_t),
+        /// Fields are written into a freshly created object that still needs
+        /// to be attached somewhere once serialization finishes.
+        Owned(DataObj<'static>),
+    }
+
+    enum Finish {
+        None,
+        Field {
+            parent: *mut obs_data_t,
+            name: ObsStringKey,
+        },
+        Element {
+            array: *mut obs_data_array_t,
+        },
+    }
+
+    type ObsStringKey = crate::string::ObsString;
+
+    struct Builder {
+        obj: BuilderObj,
+        finish: Finish,
+        pending_key: Option<String>,
+    }
+
+    impl Builder {
+        fn obj_ptr(&self) -> *mut obs_data_t {
+            match &self.obj {
+                BuilderObj::Borrowed(ptr) => *ptr,
+                BuilderObj::Owned(obj) => unsafe { obj.as_ptr_mut() },
+            }
+        }
+
+        fn finish(self) -> Result<(), Error> {
+            if let BuilderObj::Owned(obj) = &self.obj {
+                match self.finish {
+                    Finish::None => {}
+                    Finish::Field { parent, name } => unsafe {
+                        obs_data_set_obj(parent, name.as_ptr(), obj.as_ptr_mut());
+                    },
+                    Finish::Element { array } => unsafe {
+                        obs_data_array_push_back(array, obj.as_ptr_mut());
+                    },
+                }
+            }
+            Ok(())
+            // `self.obj`, if owned, drops here, releasing our local
+            // reference (the `obs_data_set_obj`/`push_back` call above took
+            // its own reference), mirroring `set_default_unchecked`.
+        }
+    }
+
+    impl SerializeStruct for Builder {
+        type Ok = ();
+        type Error = Error;
+
+        fn serialize_field<T: ?Sized + Serialize>(
+            &mut self,
+            key: &'static str,
+            value: &T,
+        ) -> Result<(), Error> {
+            value.serialize(ValueSerializer {
+                obj: self.obj_ptr(),
+                name: key.into(),
+            })
+        }
+
+        fn end(self) -> Result<(), Error> {
+            self.finish()
+        }
+    }
+
+    impl SerializeMap for Builder {
+        type Ok = ();
+        type Error = Error;
+
+        fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> Result<(), Error> {
+            self.pending_key = Some(key.serialize(KeySerializer)?);
+            Ok(())
+        }
+
+        fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+            let key = self
+                .pending_key
+                .take()
+                .ok_or_else(|| Error::Message("serialize_value called before serialize_key".into()))?;
+            value.serialize(ValueSerializer {
+                obj: self.obj_ptr(),
+                name: key.into(),
+            })
+        }
+
+        fn end(self) -> Result<(), Error> {
+            self.finish()
+        }
+    }
+
+    /// Converts a serializable map key into the string OBS data keys require.
+    struct KeySerializer;
+
+    macro_rules! key_to_string {
+        ($($fn_name:ident($t:ty))*) => {
+            $(fn $fn_name(self, v: $t) -> Result<String, Error> {
+                Ok(v.to_string())
+            })*
+        };
+    }
+
+    impl ser::Serializer for KeySerializer {
+        type Ok = String;
+        type Error = Error;
+        type SerializeSeq = ser::Impossible<String, Error>;
+        type SerializeTuple = ser::Impossible<String, Error>;
+        type SerializeTupleStruct = ser::Impossible<String, Error>;
+        type SerializeTupleVariant = ser::Impossible<String, Error>;
+        type SerializeMap = ser::Impossible<String, Error>;
+        type SerializeStruct = ser::Impossible<String, Error>;
+        type SerializeStructVariant = ser::Impossible<String, Error>;
+
+        key_to_string! {
+            serialize_i8(i8) serialize_i16(i16) serialize_i32(i32) serialize_i64(i64)
+            serialize_u8(u8) serialize_u16(u16) serialize_u32(u32) serialize_u64(u64)
+            serialize_bool(bool) serialize_char(char)
+        }
+
+        fn serialize_str(self, v: &str) -> Result<String, Error> {
+            Ok(v.to_string())
+        }
+
+        fn serialize_f32(self, _v: f32) -> Result<String, Error> {
+            Err(Error::Message("floating point map keys are not supported".into()))
+        }
+        fn serialize_f64(self, _v: f64) -> Result<String, Error> {
+            Err(Error::Message("floating point map keys are not supported".into()))
+        }
+        fn serialize_bytes(self, _v: &[u8]) -> Result<String, Error> {
+            Err(Error::Message("byte string map keys are not supported".into()))
+        }
+        fn serialize_none(self) -> Result<String, Error> {
+            Err(Error::Message("missing map key".into()))
+        }
+        fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<String, Error> {
+            value.serialize(self)
+        }
+        fn serialize_unit(self) -> Result<String, Error> {
+            Err(Error::Message("unit map keys are not supported".into()))
+        }
+        fn serialize_unit_struct(self, name: &'static str) -> Result<String, Error> {
+            Ok(name.to_string())
+        }
+        fn serialize_unit_variant(
+            self,
+            _name: &'static str,
+            _index: u32,
+            variant: &'static str,
+        ) -> Result<String, Error> {
+            Ok(variant.to_string())
+        }
+        fn serialize_newtype_struct<T: ?Sized + Serialize>(
+            self,
+            _name: &'static str,
+            value: &T,
+        ) -> Result<String, Error> {
+            value.serialize(self)
+        }
+        fn serialize_newtype_variant<T: ?Sized + Serialize>(
+            self,
+            _name: &'static str,
+            _index: u32,
+            _variant: &'static str,
+            _value: &T,
+        ) -> Result<String, Error> {
+            Err(Error::Message("enum map keys are not supported".into()))
+        }
+        fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, Error> {
+            Err(Error::Message("sequence map keys are not supported".into()))
+        }
+        fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, Error> {
+            Err(Error::Message("tuple map keys are not supported".into()))
+        }
+        fn serialize_tuple_struct(
+            self,
+            _name: &'static str,
+            _len: usize,
+        ) -> Result<Self::SerializeTupleStruct, Error> {
+            Err(Error::Message("tuple struct map keys are not supported".into()))
+        }
+        fn serialize_tuple_variant(
+            self,
+            _name: &'static str,
+            _index: u32,
+            _variant: &'static str,
+            _len: usize,
+        ) -> Result<Self::SerializeTupleVariant, Error> {
+            Err(Error::Message("enum map keys are not supported".into()))
+        }
+        fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Error> {
+            Err(Error::Message("map map keys are not supported".into()))
+        }
+        fn serialize_struct(
+            self,
+            _name: &'static str,
+            _len: usize,
+        ) -> Result<Self::SerializeStruct, Error> {
+            Err(Error::Message("struct map keys are not supported".into()))
+        }
+        fn serialize_struct_variant(
+            self,
+            _name: &'static str,
+            _index: u32,
+            _variant: &'static str,
+            _len: usize,
+        ) -> Result<Self::SerializeStructVariant, Error> {
+            Err(Error::Message("enum map keys are not supported".into()))
+        }
+    }
+
+    /// Entry point for [`DataObj::from_serialize`]: `T` must serialize as a
+    /// struct or map, directly onto `obj`.
+    struct TopSerializer {
+        obj: *mut obs_data_t,
+    }
+
+    /// Writes a single named field (`name`) into `obj`, dispatching on the
+    /// shape of the value being serialized.
+    struct ValueSerializer {
+        obj: *mut obs_data_t,
+        name: ObsStringKey,
+    }
+
+    /// Turns a single sequence element into its own [`DataObj`] and pushes it
+    /// onto `array` (every `obs_data_array_t` entry is itself an object,
+    /// there is no scalar array element type in libobs). Scalars are wrapped
+    /// as `{ "value": <scalar> }`.
+    struct ElementSerializer {
+        array: *mut obs_data_array_t,
+    }
+
+    macro_rules! forbid_non_container {
+        ($($fn_name:ident($t:ty))*) => {
+            $(fn $fn_name(self, _v: $t) -> Result<(), Error> {
+                Err(Error::Message(
+                    "top-level value must serialize as a struct or map to become a DataObj".into(),
+                ))
+            })*
+        };
+    }
+
+    impl ser::Serializer for TopSerializer {
+        type Ok = ();
+        type Error = Error;
+        type SerializeSeq = ser::Impossible<(), Error>;
+        type SerializeTuple = ser::Impossible<(), Error>;
+        type SerializeTupleStruct = ser::Impossible<(), Error>;
+        type SerializeTupleVariant = ser::Impossible<(), Error>;
+        type SerializeMap = Builder;
+        type SerializeStruct = Builder;
+        type SerializeStructVariant = ser::Impossible<(), Error>;
+
+        forbid_non_container! {
+            serialize_bool(bool) serialize_i8(i8) serialize_i16(i16) serialize_i32(i32)
+            serialize_i64(i64) serialize_u8(u8) serialize_u16(u16) serialize_u32(u32)
+            serialize_u64(u64) serialize_f32(f32) serialize_f64(f64) serialize_char(char)
+            serialize_str(&str) serialize_bytes(&[u8])
+        }
+
+        fn serialize_none(self) -> Result<(), Error> {
+            Err(Error::Message("a DataObj cannot be built from an empty value".into()))
+        }
+        fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<(), Error> {
+            value.serialize(self)
+        }
+        fn serialize_unit(self) -> Result<(), Error> {
+            Err(Error::Message("a DataObj cannot be built from a unit value".into()))
+        }
+        fn serialize_unit_struct(self, _name: &'static str) -> Result<(), Error> {
+            self.serialize_unit()
+        }
+        fn serialize_unit_variant(
+            self,
+            _name: &'static str,
+            _index: u32,
+            _variant: &'static str,
+        ) -> Result<(), Error> {
+            self.serialize_unit()
+        }
+        fn serialize_newtype_struct<T: ?Sized + Serialize>(
+            self,
+            _name: &'static str,
+            value: &T,
+        ) -> Result<(), Error> {
+            value.serialize(self)
+        }
+        fn serialize_newtype_variant<T: ?Sized + Serialize>(
+            self,
+            _name: &'static str,
+            _index: u32,
+            _variant: &'static str,
+            _value: &T,
+        ) -> Result<(), Error> {
+            Err(Error::Message("enum values cannot become a DataObj directly".into()))
+        }
+        fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, Error> {
+            Err(Error::Message("a DataObj cannot be built from a sequence".into()))
+        }
+        fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, Error> {
+            Err(Error::Message("a DataObj cannot be built from a tuple".into()))
+        }
+        fn serialize_tuple_struct(
+            self,
+            _name: &'static str,
+            _len: usize,
+        ) -> Result<Self::SerializeTupleStruct, Error> {
+            Err(Error::Message("a DataObj cannot be built from a tuple struct".into()))
+        }
+        fn serialize_tuple_variant(
+            self,
+            _name: &'static str,
+            _index: u32,
+            _variant: &'static str,
+            _len: usize,
+        ) -> Result<Self::SerializeTupleVariant, Error> {
+            Err(Error::Message("enum values cannot become a DataObj directly".into()))
+        }
+        fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Error> {
+            Ok(Builder {
+                obj: BuilderObj::Borrowed(self.obj),
+                finish: Finish::None,
+                pending_key: None,
+            })
+        }
+        fn serialize_struct(
+            self,
+            _name: &'static str,
+            _len: usize,
+        ) -> Result<Self::SerializeStruct, Error> {
+            Ok(Builder {
+                obj: BuilderObj::Borrowed(self.obj),
+                finish: Finish::None,
+                pending_key: None,
+            })
+        }
+        fn serialize_struct_variant(
+            self,
+            _name: &'static str,
+            _index: u32,
+            _variant: &'static str,
+            _len: usize,
+        ) -> Result<Self::SerializeStructVariant, Error> {
+            Err(Error::Message("enum values cannot become a DataObj directly".into()))
+        }
+    }
+
+    impl ser::Serializer for ValueSerializer {
+        type Ok = ();
+        type Error = Error;
+        type SerializeSeq = SeqBuilder;
+        type SerializeTuple = SeqBuilder;
+        type SerializeTupleStruct = SeqBuilder;
+        type SerializeTupleVariant = ser::Impossible<(), Error>;
+        type SerializeMap = Builder;
+        type SerializeStruct = Builder;
+        type SerializeStructVariant = ser::Impossible<(), Error>;
+
+        fn serialize_bool(self, v: bool) -> Result<(), Error> {
+            unsafe { obs_data_set_bool(self.obj, self.name.as_ptr(), v) };
+            Ok(())
+        }
+        fn serialize_i8(self, v: i8) -> Result<(), Error> {
+            self.serialize_i64(v as i64)
+        }
+        fn serialize_i16(self, v: i16) -> Result<(), Error> {
+            self.serialize_i64(v as i64)
+        }
+        fn serialize_i32(self, v: i32) -> Result<(), Error> {
+            self.serialize_i64(v as i64)
+        }
+        fn serialize_i64(self, v: i64) -> Result<(), Error> {
+            unsafe { obs_data_set_int(self.obj, self.name.as_ptr(), v) };
+            Ok(())
+        }
+        fn serialize_u8(self, v: u8) -> Result<(), Error> {
+            self.serialize_i64(v as i64)
+        }
+        fn serialize_u16(self, v: u16) -> Result<(), Error> {
+            self.serialize_i64(v as i64)
+        }
+        fn serialize_u32(self, v: u32) -> Result<(), Error> {
+            self.serialize_i64(v as i64)
+        }
+        fn serialize_u64(self, v: u64) -> Result<(), Error> {
+            self.serialize_i64(v as i64)
+        }
+        fn serialize_f32(self, v: f32) -> Result<(), Error> {
+            self.serialize_f64(v as f64)
+        }
+        fn serialize_f64(self, v: f64) -> Result<(), Error> {
+            unsafe { obs_data_set_double(self.obj, self.name.as_ptr(), v) };
+            Ok(())
+        }
+        fn serialize_char(self, v: char) -> Result<(), Error> {
+            self.serialize_str(v.encode_utf8(&mut [0; 4]))
+        }
+        fn serialize_str(self, v: &str) -> Result<(), Error> {
+            let v = crate::string::ObsString::from(v);
+            unsafe { obs_data_set_string(self.obj, self.name.as_ptr(), v.as_ptr()) };
+            Ok(())
+        }
+        fn serialize_bytes(self, _v: &[u8]) -> Result<(), Error> {
+            Err(Error::Message("byte strings are not supported".into()))
+        }
+        fn serialize_none(self) -> Result<(), Error> {
+            // Mirrors the absence of a null type in libobs: an absent
+            // `Option` field is simply not written.
+            Ok(())
+        }
+        fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<(), Error> {
+            value.serialize(self)
+        }
+        fn serialize_unit(self) -> Result<(), Error> {
+            Ok(())
+        }
+        fn serialize_unit_struct(self, _name: &'static str) -> Result<(), Error> {
+            Ok(())
+        }
+        fn serialize_unit_variant(
+            self,
+            _name: &'static str,
+            _index: u32,
+            variant: &'static str,
+        ) -> Result<(), Error> {
+            self.serialize_str(variant)
+        }
+        fn serialize_newtype_struct<T: ?Sized + Serialize>(
+            self,
+            _name: &'static str,
+            value: &T,
+        ) -> Result<(), Error> {
+            value.serialize(self)
+        }
+        fn serialize_newtype_variant<T: ?Sized + Serialize>(
+            self,
+            _name: &'static str,
+            _index: u32,
+            _variant: &'static str,
+            _value: &T,
+        ) -> Result<(), Error> {
+            Err(Error::Message("enum variants with data are not supported".into()))
+        }
+        fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, Error> {
+            let array = unsafe { obs_data_array_create() };
+            let array =
+                unsafe { DataArray::from_raw_unchecked(array) }.expect("obs_data_array_create");
+            Ok(SeqBuilder {
+                array,
+                parent: self.obj,
+                name: self.name,
+            })
+        }
+        fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple, Error> {
+            self.serialize_seq(Some(len))
+        }
+        fn serialize_tuple_struct(
+            self,
+            _name: &'static str,
+            len: usize,
+        ) -> Result<Self::SerializeTupleStruct, Error> {
+            self.serialize_seq(Some(len))
+        }
+        fn serialize_tuple_variant(
+            self,
+            _name: &'static str,
+            _index: u32,
+            _variant: &'static str,
+            _len: usize,
+        ) -> Result<Self::SerializeTupleVariant, Error> {
+            Err(Error::Message("enum variants with data are not supported".into()))
+        }
+        fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Error> {
+            Ok(Builder {
+                obj: BuilderObj::Owned(DataObj::new()),
+                finish: Finish::Field {
+                    parent: self.obj,
+                    name: self.name,
+                },
+                pending_key: None,
+            })
+        }
+        fn serialize_struct(
+            self,
+            _name: &'static str,
+            _len: usize,
+        ) -> Result<Self::SerializeStruct, Error> {
+            Ok(Builder {
+                obj: BuilderObj::Owned(DataObj::new()),
+                finish: Finish::Field {
+                    parent: self.obj,
+                    name: self.name,
+                },
+                pending_key: None,
+            })
+        }
+        fn serialize_struct_variant(
+            self,
+            _name: &'static str,
+            _index: u32,
+            _variant: &'static str,
+            _len: usize,
+        ) -> Result<Self::SerializeStructVariant, Error> {
+            Err(Error::Message("enum variants with data are not supported".into()))
+        }
+    }
+
+    /// Builds an array field: each element is serialized through
+    /// [`ElementSerializer`], then the finished array is attached under
+    /// `name` in `parent`.
+    struct SeqBuilder {
+        array: DataArray<'static>,
+        parent: *mut obs_data_t,
+        name: ObsStringKey,
+    }
+
+    impl SerializeSeq for SeqBuilder {
+        type Ok = ();
+        type Error = Error;
+
+        fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+            value.serialize(ElementSerializer {
+                array: unsafe { self.array.as_ptr_mut() },
+            })
+        }
+
+        fn end(self) -> Result<(), Error> {
+            unsafe { obs_data_set_array(self.parent, self.name.as_ptr(), self.array.as_ptr_mut()) };
+            Ok(())
+        }
+    }
+
+    impl SerializeTuple for SeqBuilder {
+        type Ok = ();
+        type Error = Error;
+
+        fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+            SerializeSeq::serialize_element(self, value)
+        }
+
+        fn end(self) -> Result<(), Error> {
+            SerializeSeq::end(self)
+        }
+    }
+
+    impl ser::SerializeTupleStruct for SeqBuilder {
+        type Ok = ();
+        type Error = Error;
+
+        fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+            SerializeSeq::serialize_element(self, value)
+        }
+
+        fn end(self) -> Result<(), Error> {
+            SerializeSeq::end(self)
+        }
+    }
+
+    impl ser::Serializer for ElementSerializer {
+        type Ok = ();
+        type Error = Error;
+        type SerializeSeq = ser::Impossible<(), Error>;
+        type SerializeTuple = ser::Impossible<(), Error>;
+        type SerializeTupleStruct = ser::Impossible<(), Error>;
+        type SerializeTupleVariant = ser::Impossible<(), Error>;
+        type SerializeMap = Builder;
+        type SerializeStruct = Builder;
+        type SerializeStructVariant = ser::Impossible<(), Error>;
+
+        fn serialize_bool(self, v: bool) -> Result<(), Error> {
+            self.push_scalar(|obj| {
+                let name = crate::string::ObsString::from("value");
+                unsafe { obs_data_set_bool(obj, name.as_ptr(), v) }
+            })
+        }
+        fn serialize_i8(self, v: i8) -> Result<(), Error> {
+            self.serialize_i64(v as i64)
+        }
+        fn serialize_i16(self, v: i16) -> Result<(), Error> {
+            self.serialize_i64(v as i64)
+        }
+        fn serialize_i32(self, v: i32) -> Result<(), Error> {
+            self.serialize_i64(v as i64)
+        }
+        fn serialize_i64(self, v: i64) -> Result<(), Error> {
+            self.push_scalar(|obj| {
+                let name = crate::string::ObsString::from("value");
+                unsafe { obs_data_set_int(obj, name.as_ptr(), v) }
+            })
+        }
+        fn serialize_u8(self, v: u8) -> Result<(), Error> {
+            self.serialize_i64(v as i64)
+        }
+        fn serialize_u16(self, v: u16) -> Result<(), Error> {
+            self.serialize_i64(v as i64)
+        }
+        fn serialize_u32(self, v: u32) -> Result<(), Error> {
+            self.serialize_i64(v as i64)
+        }
+        fn serialize_u64(self, v: u64) -> Result<(), Error> {
+            self.serialize_i64(v as i64)
+        }
+        fn serialize_f32(self, v: f32) -> Result<(), Error> {
+            self.serialize_f64(v as f64)
+        }
+        fn serialize_f64(self, v: f64) -> Result<(), Error> {
+            self.push_scalar(|obj| {
+                let name = crate::string::ObsString::from("value");
+                unsafe { obs_data_set_double(obj, name.as_ptr(), v) }
+            })
+        }
+        fn serialize_char(self, v: char) -> Result<(), Error> {
+            self.serialize_str(v.encode_utf8(&mut [0; 4]))
+        }
+        fn serialize_str(self, v: &str) -> Result<(), Error> {
+            let v = crate::string::ObsString::from(v);
+            self.push_scalar(|obj| {
+                let name = crate::string::ObsString::from("value");
+                unsafe { obs_data_set_string(obj, name.as_ptr(), v.as_ptr()) }
+            })
+        }
+        fn serialize_bytes(self, _v: &[u8]) -> Result<(), Error> {
+            Err(Error::Message("byte strings are not supported".into()))
+        }
+        fn serialize_none(self) -> Result<(), Error> {
+            self.push_empty()
+        }
+        fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<(), Error> {
+            value.serialize(self)
+        }
+        fn serialize_unit(self) -> Result<(), Error> {
+            self.push_empty()
+        }
+        fn serialize_unit_struct(self, _name: &'static str) -> Result<(), Error> {
+            self.push_empty()
+        }
+        fn serialize_unit_variant(
+            self,
+            _name: &'static str,
+            _index: u32,
+            variant: &'static str,
+        ) -> Result<(), Error> {
+            self.serialize_str(variant)
+        }
+        fn serialize_newtype_struct<T: ?Sized + Serialize>(
+            self,
+            _name: &'static str,
+            value: &T,
+        ) -> Result<(), Error> {
+            value.serialize(self)
+        }
+        fn serialize_newtype_variant<T: ?Sized + Serialize>(
+            self,
+            _name: &'static str,
+            _index: u32,
+            _variant: &'static str,
+            _value: &T,
+        ) -> Result<(), Error> {
+            Err(Error::Message("enum variants with data are not supported".into()))
+        }
+        fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, Error> {
+            Err(Error::Message("nested arrays are not supported".into()))
+        }
+        fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, Error> {
+            Err(Error::Message("nested arrays are not supported".into()))
+        }
+        fn serialize_tuple_struct(
+            self,
+            _name: &'static str,
+            _len: usize,
+        ) -> Result<Self::SerializeTupleStruct, Error> {
+            Err(Error::Message("nested arrays are not supported".into()))
+        }
+        fn serialize_tuple_variant(
+            self,
+            _name: &'static str,
+            _index: u32,
+            _variant: &'static str,
+            _len: usize,
+        ) -> Result<Self::SerializeTupleVariant, Error> {
+            Err(Error::Message("enum variants with data are not supported".into()))
+        }
+        fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Error> {
+            Ok(Builder {
+                obj: BuilderObj::Owned(DataObj::new()),
+                finish: Finish::Element { array: self.array },
+                pending_key: None,
+            })
+        }
+        fn serialize_struct(
+            self,
+            _name: &'static str,
+            _len: usize,
+        ) -> Result<Self::SerializeStruct, Error> {
+            Ok(Builder {
+                obj: BuilderObj::Owned(DataObj::new()),
+                finish: Finish::Element { array: self.array },
+                pending_key: None,
+            })
+        }
+        fn serialize_struct_variant(
+            self,
+            _name: &'static str,
+            _index: u32,
+            _variant: &'static str,
+            _len: usize,
+        ) -> Result<Self::SerializeStructVariant, Error> {
+            Err(Error::Message("enum variants with data are not supported".into()))
+        }
+    }
+
+    impl ElementSerializer {
+        /// Wraps a scalar as `{ "value": <scalar> }` (set via `write`) and
+        /// pushes it onto the array.
+        fn push_scalar(self, write: impl FnOnce(*mut obs_data_t)) -> Result<(), Error> {
+            let child = DataObj::new();
+            write(unsafe { child.as_ptr_mut() });
+            unsafe { obs_data_array_push_back(self.array, child.as_ptr_mut()) };
+            Ok(())
+        }
+
+        fn push_empty(self) -> Result<(), Error> {
+            let child = DataObj::new();
+            unsafe { obs_data_array_push_back(self.array, child.as_ptr_mut()) };
+            Ok(())
+        }
+    }
+
+    /// Deserializes a value from a single, already-looked-up data item.
+    struct FieldDeserializer {
+        item: DataItem,
+    }
+
+    /// Deserializes a value from a whole [`DataObj`] (the top-level object,
+    /// or an array element).
+    struct DataObjDeserializer<'a, 'p> {
+        obj: &'a DataObj<'p>,
+    }
+
+    struct ArraySeqAccess<'p> {
+        array: DataArray<'p>,
+        index: usize,
+    }
+
+    struct StructMapAccess<'a, 'p> {
+        obj: &'a DataObj<'p>,
+        fields: &'static [&'static str],
+        index: usize,
+        current: Option<DataItem>,
+    }
+
+    impl<'de> de::MapAccess<'de> for StructMapAccess<'_, '_> {
+        type Error = Error;
+
+        fn next_key_seed<K: de::DeserializeSeed<'de>>(
+            &mut self,
+            seed: K,
+        ) -> Result<Option<K::Value>, Error> {
+            while self.index < self.fields.len() {
+                let key = self.fields[self.index];
+                self.index += 1;
+                if let Some(item) = item_by_name(self.obj, key) {
+                    self.current = Some(item);
+                    return seed.deserialize(key.into_deserializer()).map(Some);
+                }
+            }
+            Ok(None)
+        }
+
+        fn next_value_seed<V: de::DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, Error> {
+            let item = self
+                .current
+                .take()
+                .expect("next_value_seed called before next_key_seed");
+            seed.deserialize(FieldDeserializer { item })
+        }
+    }
+
+    impl<'de> de::SeqAccess<'de> for ArraySeqAccess<'_> {
+        type Error = Error;
+
+        fn next_element_seed<T: de::DeserializeSeed<'de>>(
+            &mut self,
+            seed: T,
+        ) -> Result<Option<T::Value>, Error> {
+            if self.index >= self.array.len() {
+                return Ok(None);
+            }
+            let obj = self
+                .array
+                .get(self.index)
+                .ok_or_else(|| Error::Message("array element disappeared mid-iteration".into()))?;
+            self.index += 1;
+            seed.deserialize(DataObjDeserializer { obj: &obj }).map(Some)
+        }
+    }
+
+    macro_rules! forward_scalars_to_any {
+        ($($fn_name:ident)*) => {
+            $(fn $fn_name<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+                self.deserialize_any(visitor)
+            })*
+        };
+    }
+
+    impl<'de> de::Deserializer<'de> for FieldDeserializer {
+        type Error = Error;
+
+        fn deserialize_any<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+            match self.item.typ() {
+                DataType::String => visitor.visit_string(
+                    self.item
+                        .get_string()
+                        .ok_or_else(|| Error::Message("expected a UTF-8 string".into()))?,
+                ),
+                DataType::Int => visitor.visit_i64(self.item.get_int()),
+                DataType::Double => visitor.visit_f64(self.item.get_double()),
+                DataType::Boolean => visitor.visit_bool(self.item.get_bool()),
+                DataType::Object | DataType::Array => Err(Error::Message(
+                    "nested objects and arrays require a concrete struct/Vec target type".into(),
+                )),
+            }
+        }
+
+        forward_scalars_to_any! {
+            deserialize_bool deserialize_i8 deserialize_i16 deserialize_i32 deserialize_i64
+            deserialize_u8 deserialize_u16 deserialize_u32 deserialize_u64
+            deserialize_f32 deserialize_f64 deserialize_char deserialize_str deserialize_string
+            deserialize_bytes deserialize_byte_buf deserialize_unit deserialize_identifier
+            deserialize_ignored_any
+        }
+
+        fn deserialize_option<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+            // A missing field is never handed to `FieldDeserializer` (see
+            // `StructMapAccess::next_key_seed`), so a present item is always `Some`.
+            visitor.visit_some(self)
+        }
+
+        fn deserialize_unit_struct<V: de::Visitor<'de>>(
+            self,
+            _name: &'static str,
+            visitor: V,
+        ) -> Result<V::Value, Error> {
+            self.deserialize_unit(visitor)
+        }
+
+        fn deserialize_newtype_struct<V: de::Visitor<'de>>(
+            self,
+            _name: &'static str,
+            visitor: V,
+        ) -> Result<V::Value, Error> {
+            visitor.visit_newtype_struct(self)
+        }
+
+        fn deserialize_seq<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+            if self.item.typ() != DataType::Array {
+                return Err(Error::TypeMismatch {
+                    expected: DataType::Array,
+                    found: Some(self.item.typ()),
+                });
+            }
+            visitor.visit_seq(ArraySeqAccess {
+                array: self.item.get_array().expect("checked DataType::Array above"),
+                index: 0,
+            })
+        }
+
+        fn deserialize_tuple<V: de::Visitor<'de>>(
+            self,
+            _len: usize,
+            visitor: V,
+        ) -> Result<V::Value, Error> {
+            self.deserialize_seq(visitor)
+        }
+
+        fn deserialize_tuple_struct<V: de::Visitor<'de>>(
+            self,
+            _name: &'static str,
+            _len: usize,
+            visitor: V,
+        ) -> Result<V::Value, Error> {
+            self.deserialize_seq(visitor)
+        }
+
+        fn deserialize_map<V: de::Visitor<'de>>(self, _visitor: V) -> Result<V::Value, Error> {
+            Err(Error::Message(
+                "generic maps are not supported, deserialize into a concrete struct instead".into(),
+            ))
+        }
+
+        fn deserialize_struct<V: de::Visitor<'de>>(
+            self,
+            _name: &'static str,
+            fields: &'static [&'static str],
+            visitor: V,
+        ) -> Result<V::Value, Error> {
+            if self.item.typ() != DataType::Object {
+                return Err(Error::TypeMismatch {
+                    expected: DataType::Object,
+                    found: Some(self.item.typ()),
+                });
+            }
+            let obj = self.item.get_obj().expect("checked DataType::Object above");
+            visitor.visit_map(StructMapAccess {
+                obj: &obj,
+                fields,
+                index: 0,
+                current: None,
+            })
+        }
+
+        fn deserialize_enum<V: de::Visitor<'de>>(
+            self,
+            _name: &'static str,
+            _variants: &'static [&'static str],
+            visitor: V,
+        ) -> Result<V::Value, Error> {
+            let name = self
+                .item
+                .get_string()
+                .ok_or_else(|| Error::Message("expected a unit enum variant name (string)".into()))?;
+            visitor.visit_enum(name.into_deserializer())
+        }
+    }
+
+    impl<'de> de::Deserializer<'de> for DataObjDeserializer<'_, '_> {
+        type Error = Error;
+
+        fn deserialize_any<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+            // Array elements are always objects; a scalar element is wrapped
+            // as `{ "value": <scalar> }` by `ElementSerializer`.
+            match item_by_name(self.obj, "value") {
+                Some(item) => FieldDeserializer { item }.deserialize_any(visitor),
+                None => Err(Error::MissingField("value")),
+            }
+        }
+
+        forward_scalars_to_any! {
+            deserialize_bool deserialize_i8 deserialize_i16 deserialize_i32 deserialize_i64
+            deserialize_u8 deserialize_u16 deserialize_u32 deserialize_u64
+            deserialize_f32 deserialize_f64 deserialize_char deserialize_str deserialize_string
+            deserialize_bytes deserialize_byte_buf deserialize_unit deserialize_identifier
+            deserialize_ignored_any deserialize_seq
+        }
+
+        fn deserialize_option<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+            visitor.visit_some(self)
+        }
+
+        fn deserialize_unit_struct<V: de::Visitor<'de>>(
+            self,
+            _name: &'static str,
+            visitor: V,
+        ) -> Result<V::Value, Error> {
+            self.deserialize_unit(visitor)
+        }
+
+        fn deserialize_newtype_struct<V: de::Visitor<'de>>(
+            self,
+            _name: &'static str,
+            visitor: V,
+        ) -> Result<V::Value, Error> {
+            visitor.visit_newtype_struct(self)
+        }
+
+        fn deserialize_tuple<V: de::Visitor<'de>>(
+            self,
+            _len: usize,
+            visitor: V,
+        ) -> Result<V::Value, Error> {
+            self.deserialize_seq(visitor)
+        }
+
+        fn deserialize_tuple_struct<V: de::Visitor<'de>>(
+            self,
+            _name: &'static str,
+            _len: usize,
+            visitor: V,
+        ) -> Result<V::Value, Error> {
+            self.deserialize_seq(visitor)
+        }
+
+        fn deserialize_map<V: de::Visitor<'de>>(self, _visitor: V) -> Result<V::Value, Error> {
+            Err(Error::Message(
+                "generic maps are not supported, deserialize into a concrete struct instead".into(),
+            ))
+        }
+
+        fn deserialize_struct<V: de::Visitor<'de>>(
+            self,
+            _name: &'static str,
+            fields: &'static [&'static str],
+            visitor: V,
+        ) -> Result<V::Value, Error> {
+            visitor.visit_map(StructMapAccess {
+                obj: self.obj,
+                fields,
+                index: 0,
+                current: None,
+            })
+        }
+
+        fn deserialize_enum<V: de::Visitor<'de>>(
+            self,
+            _name: &'static str,
+            _variants: &'static [&'static str],
+            visitor: V,
+        ) -> Result<V::Value, Error> {
+            match item_by_name(self.obj, "value") {
+                Some(item) => FieldDeserializer { item }.deserialize_enum(_name, _variants, visitor),
+                None => Err(Error::MissingField("value")),
+            }
+        }
+    }
 }
+
+#[cfg(feature = "serde")]
+pub use serde_support::Error as SerdeError;